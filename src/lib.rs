@@ -4,6 +4,7 @@
 //! attaching immutable data to entities in the Composable Information Machine.
 
 use std::any::{Any, TypeId};
+use std::collections::HashMap;
 use std::fmt::Debug;
 
 /// Trait for components that can be attached to domain objects
@@ -23,6 +24,7 @@ use std::fmt::Debug;
 ///
 /// impl Component for Label {
 ///     fn as_any(&self) -> &dyn Any { self }
+///     fn as_any_mut(&mut self) -> &mut dyn Any { self }
 ///     fn clone_box(&self) -> Box<dyn Component> { Box::new(self.clone()) }
 ///     fn type_name(&self) -> &'static str { "Label" }
 /// }
@@ -31,11 +33,56 @@ pub trait Component: Any + Send + Sync + Debug {
     /// Get the component as Any for downcasting
     fn as_any(&self) -> &dyn Any;
 
+    /// Get the component as a mutable `Any` for downcasting
+    ///
+    /// There's no default impl possible here (a default would need to
+    /// produce `&mut dyn Any` from `&mut Self` generically, which requires
+    /// the implementation to know its own layout), so every `Component`
+    /// must provide it. This keeps the shared `&dyn Component` path
+    /// read-only while still allowing callers that own a `Box<dyn
+    /// Component>` to downcast to a mutable reference and edit in place.
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
     /// Clone the component into a box
     fn clone_box(&self) -> Box<dyn Component>;
 
     /// Get the name of this component type
     fn type_name(&self) -> &'static str;
+
+    /// Storage key used to slot this component into a `ComponentStore`
+    ///
+    /// Statically-typed components key on their Rust `TypeId`. Dynamically
+    /// registered components (see `DynamicComponent`) override this to key on
+    /// their descriptor name instead, so two dynamic components sharing a
+    /// name collide in a store just like two instances of the same Rust
+    /// type would.
+    fn storage_key(&self) -> ComponentKey {
+        ComponentKey::Type(self.as_any().type_id())
+    }
+
+    /// Serialize this component's fields to a JSON value
+    ///
+    /// Requires the `serde` feature. The default errs with
+    /// `SerializationFailed`, so enabling the feature doesn't break existing
+    /// `Component` impls that never opt into persistence; implementations
+    /// that do want to round-trip through a `ComponentRegistry` override
+    /// this, typically by delegating to `serde_json::to_value(self)`.
+    #[cfg(feature = "serde")]
+    fn serialize_erased(&self) -> ComponentResult<serde_json::Value> {
+        Err(ComponentError::SerializationFailed(format!(
+            "{} does not implement serialize_erased",
+            self.type_name()
+        )))
+    }
+}
+
+/// Identifies the storage slot a component occupies in a `ComponentStore`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ComponentKey {
+    /// Keyed by the Rust `TypeId` of a statically-typed component
+    Type(TypeId),
+    /// Keyed by the registered name of a runtime-defined component
+    Named(&'static str),
 }
 
 /// Error type for component operations
@@ -45,6 +92,8 @@ pub enum ComponentError {
     AlreadyExists(String),
     /// Component not found
     NotFound(String),
+    /// Component could not be serialized or deserialized
+    SerializationFailed(String),
 }
 
 impl std::fmt::Display for ComponentError {
@@ -52,6 +101,9 @@ impl std::fmt::Display for ComponentError {
         match self {
             ComponentError::AlreadyExists(name) => write!(f, "Component already exists: {}", name),
             ComponentError::NotFound(name) => write!(f, "Component not found: {}", name),
+            ComponentError::SerializationFailed(reason) => {
+                write!(f, "Component serialization failed: {}", reason)
+            }
         }
     }
 }
@@ -65,3 +117,894 @@ pub type ComponentResult<T> = Result<T, ComponentError>;
 pub fn component_type_id<T: Component + 'static>() -> TypeId {
     TypeId::of::<T>()
 }
+
+/// Lifecycle state of a component slot in a `ComponentStore`
+///
+/// Unmounting a component soft-removes it: the slot's data is retained, but
+/// `get`/`get_dynamic`/`iter` treat it as absent until it's remounted. This
+/// supports reversible configuration changes where removing a component
+/// temporarily shouldn't destroy its state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentState {
+    /// The slot's component is active and visible to readers
+    Mounted,
+    /// The slot's component is retained but hidden from readers
+    Unmounted,
+}
+
+#[derive(Debug)]
+struct Slot {
+    component: Box<dyn Component>,
+    state: ComponentState,
+}
+
+/// A container that attaches typed components to a single domain object
+///
+/// `ComponentStore` enforces at most one component per concrete type, mirroring
+/// the entity-attachment model used by ECS frameworks: a domain object owns a
+/// store, and each `Component` type occupies at most one slot in it.
+///
+/// # Example
+///
+/// ```
+/// use cim_component::{Component, ComponentStore};
+/// use std::any::Any;
+///
+/// #[derive(Debug, Clone)]
+/// struct Label(String);
+///
+/// impl Component for Label {
+///     fn as_any(&self) -> &dyn Any { self }
+///     fn as_any_mut(&mut self) -> &mut dyn Any { self }
+///     fn clone_box(&self) -> Box<dyn Component> { Box::new(self.clone()) }
+///     fn type_name(&self) -> &'static str { "Label" }
+/// }
+///
+/// let mut store = ComponentStore::new();
+/// store.attach(Label("widget".to_string())).unwrap();
+/// assert_eq!(store.get::<Label>().unwrap().0, "widget");
+/// ```
+#[derive(Debug, Default)]
+pub struct ComponentStore {
+    components: HashMap<ComponentKey, Slot>,
+}
+
+impl ComponentStore {
+    /// Create an empty component store
+    pub fn new() -> Self {
+        Self {
+            components: HashMap::new(),
+        }
+    }
+
+    /// Attach a component, failing if one of this type is already attached
+    ///
+    /// Keyed by `component.storage_key()` rather than `TypeId::of::<T>()`
+    /// directly, so a `DynamicComponent` attached through this generic path
+    /// still lands in the same descriptor-name keyspace `attach_dynamic`
+    /// and `get_dynamic` use, instead of colliding with every other
+    /// dynamic component on `TypeId::of::<DynamicComponent>()`.
+    pub fn attach<T: Component>(&mut self, component: T) -> ComponentResult<()> {
+        let key = component.storage_key();
+        if self.components.contains_key(&key) {
+            return Err(ComponentError::AlreadyExists(component.type_name().to_string()));
+        }
+        self.components.insert(
+            key,
+            Slot {
+                component: Box::new(component),
+                state: ComponentState::Mounted,
+            },
+        );
+        Ok(())
+    }
+
+    /// Get a reference to the attached component of type `T`, if any
+    ///
+    /// Returns `None` if the component was unmounted, even though its data
+    /// is still retained in the store.
+    pub fn get<T: Component>(&self) -> Option<&T> {
+        self.components
+            .get(&ComponentKey::Type(TypeId::of::<T>()))
+            .filter(|slot| slot.state == ComponentState::Mounted)
+            .and_then(|slot| slot.component.as_any().downcast_ref::<T>())
+    }
+
+    /// Attach a component of type `T`, overwriting any existing one of the same type
+    ///
+    /// Keyed by `component.storage_key()`; see `attach` for why.
+    pub fn replace<T: Component>(&mut self, component: T) {
+        self.components.insert(
+            component.storage_key(),
+            Slot {
+                component: Box::new(component),
+                state: ComponentState::Mounted,
+            },
+        );
+    }
+
+    /// Remove and return the attached component of type `T`
+    ///
+    /// Unlike `unmount`, this discards the component's data entirely. Use
+    /// `remove_dynamic` for components attached via `attach_dynamic` or
+    /// `attach`ed as a `DynamicComponent`, since those are keyed by
+    /// descriptor name rather than `TypeId::of::<T>()`.
+    pub fn remove<T: Component>(&mut self) -> ComponentResult<Box<dyn Component>> {
+        self.components
+            .remove(&ComponentKey::Type(TypeId::of::<T>()))
+            .map(|slot| slot.component)
+            .ok_or_else(|| ComponentError::NotFound(std::any::type_name::<T>().to_string()))
+    }
+
+    /// Mark the component of type `T` as unmounted, retaining its data
+    ///
+    /// A subsequent `remount::<T>()` restores the same data. Errors with
+    /// `NotFound` if no component of this type was ever attached. Use
+    /// `unmount_dynamic` for dynamic components, which are keyed by
+    /// descriptor name instead.
+    pub fn unmount<T: Component>(&mut self) -> ComponentResult<()> {
+        let slot = self
+            .components
+            .get_mut(&ComponentKey::Type(TypeId::of::<T>()))
+            .ok_or_else(|| ComponentError::NotFound(std::any::type_name::<T>().to_string()))?;
+        slot.state = ComponentState::Unmounted;
+        Ok(())
+    }
+
+    /// Reactivate a previously unmounted component of type `T`, restoring
+    /// its retained data
+    ///
+    /// Errors with `NotFound` if no component of this type was ever
+    /// attached. Use `remount_dynamic` for dynamic components, which are
+    /// keyed by descriptor name instead.
+    pub fn remount<T: Component>(&mut self) -> ComponentResult<()> {
+        let slot = self
+            .components
+            .get_mut(&ComponentKey::Type(TypeId::of::<T>()))
+            .ok_or_else(|| ComponentError::NotFound(std::any::type_name::<T>().to_string()))?;
+        slot.state = ComponentState::Mounted;
+        Ok(())
+    }
+
+    /// Attach a runtime-defined component, keyed by its descriptor name
+    /// rather than by `TypeId`, so two dynamic components sharing a name
+    /// collide just like two instances of the same Rust type would
+    ///
+    /// `DynamicComponent::storage_key()` already returns a name-keyed
+    /// `ComponentKey`, so this is just `attach` under a name that reads
+    /// better at dynamic-component call sites; both share one keyspace.
+    pub fn attach_dynamic(&mut self, component: DynamicComponent) -> ComponentResult<()> {
+        self.attach(component)
+    }
+
+    /// Get a reference to the attached dynamic component registered under `name`
+    ///
+    /// Returns `None` if the component was unmounted, even though its data
+    /// is still retained in the store.
+    pub fn get_dynamic(&self, name: &'static str) -> Option<&DynamicComponent> {
+        self.components
+            .get(&ComponentKey::Named(name))
+            .filter(|slot| slot.state == ComponentState::Mounted)
+            .and_then(|slot| slot.component.as_any().downcast_ref::<DynamicComponent>())
+    }
+
+    /// Remove and return the dynamic component registered under `name`
+    ///
+    /// `remove::<T>()` can't reach dynamic components: they're keyed by
+    /// descriptor name (see `storage_key`), not by `TypeId::of::<DynamicComponent>()`.
+    pub fn remove_dynamic(&mut self, name: &'static str) -> ComponentResult<Box<dyn Component>> {
+        self.components
+            .remove(&ComponentKey::Named(name))
+            .map(|slot| slot.component)
+            .ok_or_else(|| ComponentError::NotFound(name.to_string()))
+    }
+
+    /// Mark the dynamic component registered under `name` as unmounted,
+    /// retaining its data
+    ///
+    /// A subsequent `remount_dynamic(name)` restores the same data. Errors
+    /// with `NotFound` if no dynamic component was ever attached under
+    /// `name`.
+    pub fn unmount_dynamic(&mut self, name: &'static str) -> ComponentResult<()> {
+        let slot = self
+            .components
+            .get_mut(&ComponentKey::Named(name))
+            .ok_or_else(|| ComponentError::NotFound(name.to_string()))?;
+        slot.state = ComponentState::Unmounted;
+        Ok(())
+    }
+
+    /// Reactivate a previously unmounted dynamic component registered under
+    /// `name`, restoring its retained data
+    ///
+    /// Errors with `NotFound` if no dynamic component was ever attached
+    /// under `name`.
+    pub fn remount_dynamic(&mut self, name: &'static str) -> ComponentResult<()> {
+        let slot = self
+            .components
+            .get_mut(&ComponentKey::Named(name))
+            .ok_or_else(|| ComponentError::NotFound(name.to_string()))?;
+        slot.state = ComponentState::Mounted;
+        Ok(())
+    }
+
+    /// Iterate over all mounted components as `(type_name, component)` pairs
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &dyn Component)> {
+        self.components
+            .values()
+            .filter(|slot| slot.state == ComponentState::Mounted)
+            .map(|slot| (slot.component.type_name(), slot.component.as_ref()))
+    }
+}
+
+/// A runtime value stored in a `DynamicComponent` field
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DynamicValue {
+    /// A boolean field value
+    Bool(bool),
+    /// An integer field value
+    Int(i64),
+    /// A floating-point field value
+    Float(f64),
+    /// A text field value
+    Text(String),
+}
+
+/// Declares one field of a `DynamicComponent`'s schema
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldSchema {
+    /// The field's name
+    pub name: String,
+    /// A descriptive tag for the field's expected value kind, e.g. `"int"`
+    pub kind: &'static str,
+}
+
+/// Describes a runtime-defined component: its registered name, the concrete
+/// Rust type it stands in for (if any), and its field schema
+///
+/// A `ComponentDescriptor` is the schema; `DynamicComponent` is the value
+/// produced from it. Two components sharing a descriptor name are treated
+/// as the same component type, even though neither has a compiled-in Rust
+/// type backing it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComponentDescriptor {
+    /// The name this component is registered and looked up under
+    pub name: &'static str,
+    /// The concrete Rust type this descriptor stands in for, if one exists
+    pub type_id: Option<TypeId>,
+    /// The fields this component's values are expected to populate
+    pub fields: Vec<FieldSchema>,
+}
+
+impl ComponentDescriptor {
+    /// Create an empty descriptor with no fields
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            type_id: None,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Declare a field on this descriptor
+    pub fn with_field(mut self, name: impl Into<String>, kind: &'static str) -> Self {
+        self.fields.push(FieldSchema {
+            name: name.into(),
+            kind,
+        });
+        self
+    }
+}
+
+/// A component whose shape is described by a `ComponentDescriptor` at load
+/// time, rather than compiled in as a Rust type
+///
+/// This lets CIM attach data whose shape is only known at runtime (for
+/// example, loaded from an external schema) while still flowing through the
+/// same `Component` APIs as statically-typed components.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynamicComponent {
+    descriptor: ComponentDescriptor,
+    values: HashMap<String, DynamicValue>,
+}
+
+impl DynamicComponent {
+    /// Create a dynamic component from a descriptor with no field values set
+    pub fn new(descriptor: ComponentDescriptor) -> Self {
+        Self {
+            descriptor,
+            values: HashMap::new(),
+        }
+    }
+
+    /// The descriptor this component was created from
+    pub fn descriptor(&self) -> &ComponentDescriptor {
+        &self.descriptor
+    }
+
+    /// Set the value of one of the descriptor's fields
+    pub fn set_field(&mut self, name: impl Into<String>, value: DynamicValue) {
+        self.values.insert(name.into(), value);
+    }
+
+    /// Get the value of one of the descriptor's fields
+    pub fn field(&self, name: &str) -> Option<&DynamicValue> {
+        self.values.get(name)
+    }
+}
+
+impl Component for DynamicComponent {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Component> {
+        Box::new(self.clone())
+    }
+
+    fn type_name(&self) -> &'static str {
+        self.descriptor.name
+    }
+
+    fn storage_key(&self) -> ComponentKey {
+        ComponentKey::Named(self.descriptor.name)
+    }
+
+    #[cfg(feature = "serde")]
+    fn serialize_erased(&self) -> ComponentResult<serde_json::Value> {
+        serde_json::to_value(&self.values)
+            .map_err(|e| ComponentError::SerializationFailed(e.to_string()))
+    }
+}
+
+/// Register a runtime-defined component descriptor, producing an empty
+/// `DynamicComponent` ready to have its fields populated
+///
+/// This is the entry point for defining components from external schemas
+/// at load time rather than compiling them in as Rust types.
+pub fn register_dynamic(descriptor: ComponentDescriptor) -> DynamicComponent {
+    DynamicComponent::new(descriptor)
+}
+
+/// Maps a component's registered type name to a deserialization function, so
+/// a `Box<dyn Component>` can be round-tripped despite its concrete type
+/// being erased
+///
+/// Requires the `serde` feature. A component is serialized as
+/// `{ "type": <type_name>, "data": <fields> }`; `deserialize` reads the
+/// `"type"` tag back out to find the registered reconstruction function for
+/// `"data"`. This is the piece needed to save and load entity components to
+/// an event store.
+#[cfg(feature = "serde")]
+#[derive(Default)]
+pub struct ComponentRegistry {
+    deserializers: HashMap<String, fn(&serde_json::Value) -> ComponentResult<Box<dyn Component>>>,
+}
+
+#[cfg(feature = "serde")]
+impl ComponentRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self {
+            deserializers: HashMap::new(),
+        }
+    }
+
+    /// Register a component type so it can be rebuilt from its serialized form
+    ///
+    /// `type_name` must match what `T::type_name()` returns on actual
+    /// instances: it's the `"type"` tag that `serialize` writes and that
+    /// `deserialize` looks the reconstruction function up by. It's taken as
+    /// a parameter rather than read off a throwaway `T::default()` instance
+    /// so components with required fields and no sensible `Default` (e.g.
+    /// ones that must be constructed with real data) can still be
+    /// registered for persistence.
+    pub fn register<T>(&mut self, type_name: &'static str)
+    where
+        T: Component + serde::Serialize + serde::de::DeserializeOwned,
+    {
+        self.deserializers.insert(type_name.to_string(), |value| {
+            serde_json::from_value::<T>(value.clone())
+                .map(|c| Box::new(c) as Box<dyn Component>)
+                .map_err(|e| ComponentError::SerializationFailed(e.to_string()))
+        });
+    }
+
+    /// Serialize a component to `{ "type": type_name, "data": ... }`
+    pub fn serialize(&self, component: &dyn Component) -> ComponentResult<serde_json::Value> {
+        let data = component.serialize_erased()?;
+        Ok(serde_json::json!({
+            "type": component.type_name(),
+            "data": data,
+        }))
+    }
+
+    /// Rebuild a `Box<dyn Component>` from its serialized `{ "type", "data" }` form
+    pub fn deserialize(&self, value: &serde_json::Value) -> ComponentResult<Box<dyn Component>> {
+        let type_name = value
+            .get("type")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| ComponentError::NotFound("type".to_string()))?;
+        let data = value
+            .get("data")
+            .ok_or_else(|| ComponentError::NotFound("data".to_string()))?;
+        let deserialize_fn = self
+            .deserializers
+            .get(type_name)
+            .ok_or_else(|| ComponentError::NotFound(type_name.to_string()))?;
+        deserialize_fn(data)
+    }
+}
+
+/// Per-type function pointers enabling reflection-like operations on
+/// type-erased components without the concrete type in scope
+///
+/// Built once via `ComponentVtable::for_type::<T>()`, a vtable lets code
+/// that only has a `&dyn Component` and a `TypeId` still clone, debug-format,
+/// or compare the underlying value.
+#[derive(Debug, Clone, Copy)]
+pub struct ComponentVtable {
+    type_id: TypeId,
+    name: &'static str,
+    clone_box: fn(&dyn Component) -> Box<dyn Component>,
+    debug_fmt: fn(&dyn Component, &mut std::fmt::Formatter<'_>) -> std::fmt::Result,
+    eq: fn(&dyn Component, &dyn Component) -> bool,
+}
+
+impl ComponentVtable {
+    /// Build a vtable for a concrete component type
+    pub fn for_type<T: Component + Clone + PartialEq>() -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            name: std::any::type_name::<T>(),
+            clone_box: |c| c.clone_box(),
+            debug_fmt: |c, f| std::fmt::Debug::fmt(c, f),
+            eq: |a, b| {
+                match (a.as_any().downcast_ref::<T>(), b.as_any().downcast_ref::<T>()) {
+                    (Some(a), Some(b)) => a == b,
+                    _ => false,
+                }
+            },
+        }
+    }
+
+    /// The `TypeId` this vtable was built for
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// The Rust path name of the type this vtable was built for
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Clone a component through this vtable
+    pub fn clone_component(&self, component: &dyn Component) -> Box<dyn Component> {
+        (self.clone_box)(component)
+    }
+
+    /// Debug-format a component through this vtable
+    pub fn debug_component(
+        &self,
+        component: &dyn Component,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        (self.debug_fmt)(component, f)
+    }
+
+    /// Compare two components for equality through this vtable
+    ///
+    /// Returns `false` if either component fails to downcast to the type
+    /// this vtable was built for.
+    pub fn eq_components(&self, a: &dyn Component, b: &dyn Component) -> bool {
+        (self.eq)(a, b)
+    }
+}
+
+/// Registry mapping component names and `TypeId`s to their reflection vtables
+///
+/// This gives the crate reflection-like abilities over erased components:
+/// tooling can enumerate registered component kinds, look up a type by a
+/// string name received over the wire, and dispatch clone/debug/eq without
+/// the concrete type in scope — capabilities `component_type_id` alone
+/// cannot provide.
+#[derive(Debug, Default)]
+pub struct ComponentManager {
+    vtables: HashMap<TypeId, ComponentVtable>,
+    names: HashMap<TypeId, &'static str>,
+    by_name: HashMap<&'static str, TypeId>,
+}
+
+impl ComponentManager {
+    /// Create an empty manager
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a component type under a wire name, building its vtable
+    pub fn register_component<T: Component + Clone + PartialEq>(&mut self, name: &'static str) {
+        let vtable = ComponentVtable::for_type::<T>();
+        let type_id = vtable.type_id();
+        self.vtables.insert(type_id, vtable);
+        self.names.insert(type_id, name);
+        self.by_name.insert(name, type_id);
+    }
+
+    /// Whether a component type has been registered
+    pub fn is_registered<T: Component>(&self) -> bool {
+        self.vtables.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Look up the registered wire name for a `TypeId`
+    pub fn component_name(&self, type_id: &TypeId) -> Option<&str> {
+        self.names.get(type_id).copied()
+    }
+
+    /// Look up the `TypeId` registered under a wire name
+    pub fn name_to_type_id(&self, name: &str) -> Option<TypeId> {
+        self.by_name.get(name).copied()
+    }
+
+    /// Look up the vtable registered for a `TypeId`
+    pub fn vtable(&self, type_id: &TypeId) -> Option<&ComponentVtable> {
+        self.vtables.get(type_id)
+    }
+
+    /// Iterate over all registered components as `(wire_name, vtable)` pairs
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &ComponentVtable)> {
+        self.names
+            .iter()
+            .map(move |(type_id, name)| (*name, &self.vtables[type_id]))
+    }
+}
+
+/// Derive a modified component from an existing one without mutating it
+///
+/// Downcasts `component` to `T`, clones it, applies `f` to the clone, and
+/// boxes the result. The original `component` is left untouched, so the
+/// shared `&dyn Component` path stays read-only while callers that own a
+/// copy can still get ergonomic in-place edits. Returns `NotFound` if
+/// `component` isn't actually a `T`.
+pub fn with<T, F>(component: &dyn Component, f: F) -> ComponentResult<Box<dyn Component>>
+where
+    T: Component + Clone,
+    F: FnOnce(&mut T),
+{
+    let mut clone = component
+        .as_any()
+        .downcast_ref::<T>()
+        .cloned()
+        .ok_or_else(|| ComponentError::NotFound(component.type_name().to_string()))?;
+    f(&mut clone);
+    Ok(Box::new(clone))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Label(String);
+
+    impl Component for Label {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+
+        fn clone_box(&self) -> Box<dyn Component> {
+            Box::new(self.clone())
+        }
+
+        fn type_name(&self) -> &'static str {
+            "Label"
+        }
+    }
+
+    #[test]
+    fn attach_then_get_returns_the_same_value() {
+        let mut store = ComponentStore::new();
+        store.attach(Label("widget".to_string())).unwrap();
+        assert_eq!(store.get::<Label>().unwrap().0, "widget");
+    }
+
+    #[test]
+    fn attach_twice_fails_with_already_exists() {
+        let mut store = ComponentStore::new();
+        store.attach(Label("widget".to_string())).unwrap();
+        let err = store.attach(Label("other".to_string())).unwrap_err();
+        assert_eq!(err, ComponentError::AlreadyExists("Label".to_string()));
+    }
+
+    #[test]
+    fn replace_overwrites_the_existing_component() {
+        let mut store = ComponentStore::new();
+        store.attach(Label("widget".to_string())).unwrap();
+        store.replace(Label("replaced".to_string()));
+        assert_eq!(store.get::<Label>().unwrap().0, "replaced");
+    }
+
+    #[test]
+    fn remove_returns_the_boxed_component_and_clears_the_slot() {
+        let mut store = ComponentStore::new();
+        store.attach(Label("widget".to_string())).unwrap();
+        let removed = store.remove::<Label>().unwrap();
+        assert_eq!(removed.as_any().downcast_ref::<Label>().unwrap().0, "widget");
+        assert!(store.get::<Label>().is_none());
+    }
+
+    #[test]
+    fn remove_missing_component_fails_with_not_found() {
+        let mut store = ComponentStore::new();
+        assert!(matches!(
+            store.remove::<Label>(),
+            Err(ComponentError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn dynamic_components_with_different_names_do_not_collide() {
+        let mut store = ComponentStore::new();
+        store
+            .attach(register_dynamic(ComponentDescriptor::new("A")))
+            .unwrap();
+        store
+            .attach(register_dynamic(ComponentDescriptor::new("B")))
+            .unwrap();
+
+        assert!(store.get_dynamic("A").is_some());
+        assert!(store.get_dynamic("B").is_some());
+    }
+
+    #[test]
+    fn dynamic_components_with_the_same_name_do_collide() {
+        let mut store = ComponentStore::new();
+        store
+            .attach(register_dynamic(ComponentDescriptor::new("A")))
+            .unwrap();
+        let err = store
+            .attach(register_dynamic(ComponentDescriptor::new("A")))
+            .unwrap_err();
+        assert_eq!(err, ComponentError::AlreadyExists("A".to_string()));
+    }
+
+    #[test]
+    fn attach_and_attach_dynamic_share_the_same_keyspace() {
+        let mut store = ComponentStore::new();
+        store
+            .attach_dynamic(register_dynamic(ComponentDescriptor::new("A")))
+            .unwrap();
+        let err = store
+            .attach(register_dynamic(ComponentDescriptor::new("A")))
+            .unwrap_err();
+        assert_eq!(err, ComponentError::AlreadyExists("A".to_string()));
+    }
+
+    #[test]
+    fn dynamic_component_can_be_removed_by_name() {
+        let mut store = ComponentStore::new();
+        store
+            .attach_dynamic(register_dynamic(ComponentDescriptor::new("A")))
+            .unwrap();
+        assert!(store.remove_dynamic("A").is_ok());
+        assert!(store.get_dynamic("A").is_none());
+    }
+
+    #[test]
+    fn dynamic_component_can_be_unmounted_and_remounted_by_name() {
+        let mut store = ComponentStore::new();
+        let mut component = register_dynamic(ComponentDescriptor::new("A"));
+        component.set_field("x", DynamicValue::Int(42));
+        store.attach_dynamic(component).unwrap();
+
+        store.unmount_dynamic("A").unwrap();
+        assert!(store.get_dynamic("A").is_none());
+
+        store.remount_dynamic("A").unwrap();
+        assert_eq!(
+            store.get_dynamic("A").unwrap().field("x"),
+            Some(&DynamicValue::Int(42))
+        );
+    }
+
+    #[test]
+    fn remove_dynamic_missing_component_fails_with_not_found() {
+        let mut store = ComponentStore::new();
+        assert!(matches!(
+            store.remove_dynamic("missing"),
+            Err(ComponentError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn unmount_hides_the_component_from_get() {
+        let mut store = ComponentStore::new();
+        store.attach(Label("widget".to_string())).unwrap();
+        store.unmount::<Label>().unwrap();
+        assert!(store.get::<Label>().is_none());
+    }
+
+    #[test]
+    fn remount_restores_the_same_data() {
+        let mut store = ComponentStore::new();
+        store.attach(Label("widget".to_string())).unwrap();
+        store.unmount::<Label>().unwrap();
+        store.remount::<Label>().unwrap();
+        assert_eq!(store.get::<Label>().unwrap().0, "widget");
+    }
+
+    #[test]
+    fn unmount_missing_component_fails_with_not_found() {
+        let mut store = ComponentStore::new();
+        assert!(matches!(
+            store.unmount::<Label>(),
+            Err(ComponentError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn remount_missing_component_fails_with_not_found() {
+        let mut store = ComponentStore::new();
+        assert!(matches!(
+            store.remount::<Label>(),
+            Err(ComponentError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn unmounted_component_is_excluded_from_iter() {
+        let mut store = ComponentStore::new();
+        store.attach(Label("widget".to_string())).unwrap();
+        store.unmount::<Label>().unwrap();
+        assert_eq!(store.iter().count(), 0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    struct Score(u32);
+
+    #[cfg(feature = "serde")]
+    impl Component for Score {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+
+        fn clone_box(&self) -> Box<dyn Component> {
+            Box::new(self.clone())
+        }
+
+        fn type_name(&self) -> &'static str {
+            "Score"
+        }
+
+        fn serialize_erased(&self) -> ComponentResult<serde_json::Value> {
+            serde_json::to_value(self)
+                .map_err(|e| ComponentError::SerializationFailed(e.to_string()))
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn register_then_serialize_then_deserialize_round_trips() {
+        let mut registry = ComponentRegistry::new();
+        registry.register::<Score>("Score");
+
+        let boxed: Box<dyn Component> = Box::new(Score(42));
+        let value = registry.serialize(boxed.as_ref()).unwrap();
+        let restored = registry.deserialize(&value).unwrap();
+
+        assert_eq!(restored.as_any().downcast_ref::<Score>().unwrap().0, 42);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialize_unregistered_type_fails_with_not_found() {
+        let registry = ComponentRegistry::new();
+        let value = serde_json::json!({ "type": "Unregistered", "data": {} });
+        assert!(matches!(
+            registry.deserialize(&value),
+            Err(ComponentError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn vtable_clones_and_debug_formats_through_type_erasure() {
+        let vtable = ComponentVtable::for_type::<Label>();
+        let component: Box<dyn Component> = Box::new(Label("widget".to_string()));
+
+        let cloned = vtable.clone_component(component.as_ref());
+        assert_eq!(
+            cloned.as_any().downcast_ref::<Label>().unwrap().0,
+            "widget"
+        );
+
+        struct ViaVtable<'a>(&'a ComponentVtable, &'a dyn Component);
+
+        impl std::fmt::Debug for ViaVtable<'_> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.debug_component(self.1, f)
+            }
+        }
+
+        assert_eq!(
+            format!("{:?}", ViaVtable(&vtable, component.as_ref())),
+            "Label(\"widget\")"
+        );
+    }
+
+    #[test]
+    fn vtable_eq_components_compares_by_concrete_type() {
+        let vtable = ComponentVtable::for_type::<Label>();
+        let a: Box<dyn Component> = Box::new(Label("widget".to_string()));
+        let b: Box<dyn Component> = Box::new(Label("widget".to_string()));
+        let c: Box<dyn Component> = Box::new(Label("other".to_string()));
+
+        assert!(vtable.eq_components(a.as_ref(), b.as_ref()));
+        assert!(!vtable.eq_components(a.as_ref(), c.as_ref()));
+    }
+
+    #[test]
+    fn manager_registers_and_looks_up_by_name_and_type_id() {
+        let mut manager = ComponentManager::new();
+        assert!(!manager.is_registered::<Label>());
+
+        manager.register_component::<Label>("Label");
+        assert!(manager.is_registered::<Label>());
+
+        let type_id = TypeId::of::<Label>();
+        assert_eq!(manager.component_name(&type_id), Some("Label"));
+        assert_eq!(manager.name_to_type_id("Label"), Some(type_id));
+    }
+
+    #[test]
+    fn with_mutates_a_clone_and_leaves_the_original_untouched() {
+        let original = Label("widget".to_string());
+        let updated = with::<Label, _>(&original, |label| label.0 = "updated".to_string()).unwrap();
+
+        assert_eq!(original.0, "widget");
+        assert_eq!(
+            updated.as_any().downcast_ref::<Label>().unwrap().0,
+            "updated"
+        );
+    }
+
+    #[test]
+    fn with_on_downcast_mismatch_fails_with_not_found() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Size(u32);
+
+        impl Component for Size {
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn Any {
+                self
+            }
+
+            fn clone_box(&self) -> Box<dyn Component> {
+                Box::new(self.clone())
+            }
+
+            fn type_name(&self) -> &'static str {
+                "Size"
+            }
+        }
+
+        let original = Label("widget".to_string());
+        let err = with::<Size, _>(&original, |size| size.0 = 1).unwrap_err();
+        assert_eq!(err, ComponentError::NotFound("Label".to_string()));
+    }
+}